@@ -30,6 +30,7 @@ import {
   ReplayTap,
   DEFAULT_GUARDRAILS,
   SHOOT_PROGRAM_ID,
+  SHOOT_EVENT_AUTHORITY,
 } from "../../sdk/dist/index.js";
 import type { Bar, Verdict, Playbook } from "../../sdk/dist/core/types.js";
 
@@ -522,6 +523,8 @@ export function createSurfpoolTools(): ToolDefinition[] {
             { pubkey: ctx.agentKeypair.publicKey, isSigner: true, isWritable: true },
             { pubkey: agentPda, isSigner: false, isWritable: true },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
           ],
           data: Buffer.concat([
             disc("register_agent"),
@@ -596,6 +599,8 @@ export function createSurfpoolTools(): ToolDefinition[] {
             { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
             { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
           ],
           data: Buffer.concat([
             disc("initialize_challenge"),
@@ -660,6 +665,8 @@ export function createSurfpoolTools(): ToolDefinition[] {
             { pubkey: ctx.vaultPda, isSigner: false, isWritable: true },
             { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
           ],
           data: Buffer.concat([disc("enroll"), encodeU64(equity)]),
         });
@@ -772,6 +779,8 @@ export function createSurfpoolTools(): ToolDefinition[] {
             { pubkey: ctx.resultAuthorityKeypair.publicKey, isSigner: true, isWritable: false },
             { pubkey: ctx.challengePda, isSigner: false, isWritable: false },
             { pubkey: ctx.enrollmentPda, isSigner: false, isWritable: true },
+            { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
           ],
           data: Buffer.concat([
             disc("submit_result"),
@@ -817,6 +826,8 @@ export function createSurfpoolTools(): ToolDefinition[] {
             { pubkey: ctx.resultAuthorityKeypair.publicKey, isSigner: true, isWritable: false },
             { pubkey: ctx.challengePda, isSigner: false, isWritable: false },
             { pubkey: ctx.agentPda, isSigner: false, isWritable: true },
+            { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+            { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
           ],
           data: statsData,
         });
@@ -11,13 +11,23 @@ import {
   TransactionInstruction,
   SYSVAR_RENT_PUBKEY,
 } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 import { Buffer } from "buffer";
 
 export const SHOOT_PROGRAM_ID = new PublicKey(
   "4HVnwG8iz7wdUbEQDH8cYGD6EuxNmMuEbvCrz8Ke2iMG"
 );
 
+// Anchor event-CPI authority — appended (with the program id) to every
+// instruction's accounts so events are emitted via self-CPI.
+export const SHOOT_EVENT_AUTHORITY = PublicKey.findProgramAddressSync(
+  [Buffer.from("__event_authority")],
+  SHOOT_PROGRAM_ID
+)[0];
+
 // USDC mint addresses
 export const USDC_MINT_MAINNET = new PublicKey(
   "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
@@ -110,6 +120,8 @@ export async function buildEnrollInstruction(params: {
       { pubkey: vault, isSigner: false, isWritable: true },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -150,6 +162,8 @@ export async function buildSubmitResultInstruction(params: {
       { pubkey: authority, isSigner: true, isWritable: false },
       { pubkey: challenge, isSigner: false, isWritable: false },
       { pubkey: enrollment, isSigner: false, isWritable: true },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -158,6 +172,8 @@ export async function buildSubmitResultInstruction(params: {
 /**
  * Build a `settle_challenge` instruction.
  * Transfers USDC payout from vault to trader. Enrollment must have Passed status.
+ * `traderUsdc` must be the trader's USDC ATA; the program creates it (paid by
+ * the authority) if it does not exist.
  */
 export async function buildSettleChallengeInstruction(params: {
   authority: PublicKey;
@@ -165,10 +181,18 @@ export async function buildSettleChallengeInstruction(params: {
   trader: PublicKey;
   traderUsdc: PublicKey;
   vault: PublicKey;
+  usdcMint: PublicKey;
   payoutUsdc: bigint;
 }): Promise<TransactionInstruction> {
-  const { authority, challenge, trader, traderUsdc, vault, payoutUsdc } =
-    params;
+  const {
+    authority,
+    challenge,
+    trader,
+    traderUsdc,
+    vault,
+    usdcMint,
+    payoutUsdc,
+  } = params;
   const [enrollment] = findEnrollmentPda(challenge, trader);
 
   const discriminator = await instructionDiscriminator("settle_challenge");
@@ -179,7 +203,7 @@ export async function buildSettleChallengeInstruction(params: {
   return new TransactionInstruction({
     programId: SHOOT_PROGRAM_ID,
     keys: [
-      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: authority, isSigner: true, isWritable: true },
       { pubkey: challenge, isSigner: false, isWritable: false },
       { pubkey: enrollment, isSigner: false, isWritable: true },
       { pubkey: trader, isSigner: false, isWritable: true },
@@ -187,6 +211,14 @@ export async function buildSettleChallengeInstruction(params: {
       { pubkey: vault, isSigner: false, isWritable: true },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: usdcMint, isSigner: false, isWritable: false },
+      {
+        pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -223,6 +255,8 @@ export async function buildClaimFundedStatusInstruction(params: {
       { pubkey: enrollment, isSigner: false, isWritable: false },
       { pubkey: fundedTrader, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -261,6 +295,8 @@ export async function buildRegisterAgentInstruction(params: {
       { pubkey: owner, isSigner: true, isWritable: true },
       { pubkey: agent, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
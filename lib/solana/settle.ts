@@ -114,6 +114,7 @@ export async function executeSettlements(params: {
           trader: traderPk,
           traderUsdc,
           vault: vaultPda,
+          usdcMint: getUsdcMint(),
           payoutUsdc: entry.payoutUsdc,
         });
         instructions.push(settleIx);
@@ -13,9 +13,21 @@
 //! - **result_authority**: Submits off-chain scoring results and triggers
 //!   settlement payouts. This is a hot wallet controlled by the backend.
 //!
-//! Neither authority alone can steal funds — the admin creates challenges and
-//! the result_authority settles them, but settlement only pays the trader
-//! (never back to the authority).
+//! If the admin configures a liveness horizon and then stops checking in,
+//! anyone may pause new enrollments on that challenge (dead-man switch).
+//! Every admin-signed challenge instruction counts as a check-in.
+//!
+//! Neither authority alone can steal challenge funds — the admin creates
+//! challenges and the result_authority settles them, but settlement only pays
+//! the trader (never back to the authority). The bug bounty escrow is the
+//! exception: its payouts are trusted to the admin alone.
+//!
+//! ## Events
+//!
+//! Events are emitted through a self-CPI signed by the `__event_authority`
+//! PDA (`emit_cpi!`), so indexers can recover them from inner instruction
+//! data even when transaction logs are truncated. Every instruction takes
+//! the event authority and the program itself as its last two accounts.
 //!
 //! ## Token
 //!
@@ -29,8 +41,17 @@
 //! 24/7. Each agent has a strategy hash, ELO rating, and performance stats.
 //! Agents participate in challenges alongside human traders using the same
 //! scoring mechanics.
+//!
+//! ## Bug Bounty
+//!
+//! Each admin can open a USDC bounty escrow that anyone may fund. Whitehat
+//! payouts go straight from the escrow with an event carrying the report
+//! hash, so disclosure rewards leave a public trail. Bounty payouts are
+//! trusted to the admin: `reward_whitehat` can send up to the full escrow
+//! balance to any USDC token account, including the admin's own.
 
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 declare_id!("4HVnwG8iz7wdUbEQDH8cYGD6EuxNmMuEbvCrz8Ke2iMG");
@@ -63,7 +84,9 @@ pub const CHALLENGE_SPACE: usize = 8  // discriminator
     + 1   // bump
     + 1   // vault_bump
     + 1   // paused
-    + 64; // padding
+    + 8   // admin_last_check_in
+    + 8   // admin_liveness_horizon
+    + 48; // padding
 
 pub const ENROLLMENT_SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 4 + 2 + 8 + 8 + 1 + 16;
 
@@ -85,6 +108,18 @@ pub const AGENT_SPACE: usize = 8  // discriminator
     + 1   // bump
     + 32; // padding
 
+pub const BOUNTY_SPACE: usize = 8  // discriminator
+    + 32  // admin
+    + 32  // usdc_mint
+    + 32  // vault
+    + 8   // total_funded_usdc
+    + 8   // total_paid_usdc
+    + 4   // payout_count
+    + 8   // created_at
+    + 1   // bump
+    + 1   // vault_bump
+    + 32; // padding
+
 #[program]
 pub mod shoot {
     use super::*;
@@ -138,8 +173,10 @@ pub mod shoot {
         challenge.bump = ctx.bumps.challenge;
         challenge.vault_bump = ctx.bumps.vault;
         challenge.paused = false;
+        challenge.admin_last_check_in = challenge.created_at;
+        challenge.admin_liveness_horizon = 0;
 
-        emit!(ChallengeCreated {
+        emit_cpi!(ChallengeCreated {
             challenge: challenge.key(),
             admin: challenge.admin,
             challenge_id: challenge.challenge_id.clone(),
@@ -194,7 +231,7 @@ pub mod shoot {
             .checked_add(1)
             .ok_or(ShootError::Overflow)?;
 
-        emit!(TraderEnrolled {
+        emit_cpi!(TraderEnrolled {
             challenge: challenge.key(),
             trader: ctx.accounts.trader.key(),
             starting_equity_usd,
@@ -227,7 +264,7 @@ pub mod shoot {
         enrollment.final_drawdown_bps = final_drawdown_bps;
         enrollment.result_submitted_at = Clock::get()?.unix_timestamp;
 
-        emit!(ResultSubmitted {
+        emit_cpi!(ResultSubmitted {
             challenge: ctx.accounts.challenge.key(),
             trader: enrollment.trader,
             status,
@@ -277,7 +314,7 @@ pub mod shoot {
             )?;
         }
 
-        emit!(ChallengeSettled {
+        emit_cpi!(ChallengeSettled {
             challenge: ctx.accounts.challenge.key(),
             trader: ctx.accounts.trader.key(),
             payout_usdc,
@@ -320,7 +357,7 @@ pub mod shoot {
         funded.qualifying_challenge = ctx.accounts.challenge.key();
         funded.bump = ctx.bumps.funded_trader;
 
-        emit!(FundedStatusClaimed {
+        emit_cpi!(FundedStatusClaimed {
             trader: ctx.accounts.trader.key(),
             level,
             revenue_share_bps,
@@ -332,6 +369,7 @@ pub mod shoot {
 
     /// Update challenge status with state machine enforcement.
     /// Only admin can call this. Transitions: Active → Settling → Closed.
+    /// Counts as an admin liveness check-in.
     pub fn update_challenge_status(
         ctx: Context<UpdateChallengeStatus>,
         new_status: ChallengeStatus,
@@ -345,8 +383,9 @@ pub mod shoot {
         require!(valid_transition, ShootError::InvalidStatusTransition);
 
         challenge.status = new_status.clone();
+        challenge.admin_last_check_in = Clock::get()?.unix_timestamp;
 
-        emit!(ChallengeStatusChanged {
+        emit_cpi!(ChallengeStatusChanged {
             challenge: challenge.key(),
             new_status,
         });
@@ -356,10 +395,16 @@ pub mod shoot {
 
     /// Pause or unpause a challenge. Paused challenges reject new enrollments
     /// but existing enrollments continue. Only admin can call this.
+    /// Counts as an admin liveness check-in.
     pub fn pause_challenge(ctx: Context<UpdateChallengeStatus>, paused: bool) -> Result<()> {
-        ctx.accounts.challenge.paused = paused;
+        let challenge = &mut ctx.accounts.challenge;
+        challenge.paused = paused;
+        // Any admin-signed action proves liveness; without this, unpausing
+        // after the dead-man switch fired would leave it immediately
+        // re-triggerable.
+        challenge.admin_last_check_in = Clock::get()?.unix_timestamp;
 
-        emit!(ChallengePaused {
+        emit_cpi!(ChallengePaused {
             challenge: ctx.accounts.challenge.key(),
             paused,
         });
@@ -367,6 +412,75 @@ pub mod shoot {
         Ok(())
     }
 
+    // ── Admin Liveness ───────────────────────────────────────────────────
+
+    /// Record an admin liveness check-in. Only admin can call this.
+    /// Liveness is tracked per challenge, so an admin running several
+    /// challenges must check in on each of them.
+    pub fn admin_check_in(ctx: Context<UpdateChallengeStatus>) -> Result<()> {
+        let challenge = &mut ctx.accounts.challenge;
+        challenge.admin_last_check_in = Clock::get()?.unix_timestamp;
+
+        emit_cpi!(AdminCheckedIn {
+            challenge: challenge.key(),
+            admin: challenge.admin,
+            checked_in_at: challenge.admin_last_check_in,
+        });
+
+        Ok(())
+    }
+
+    /// Configure the dead-man switch horizon. If the admin goes longer than
+    /// `horizon_seconds` without checking in, anyone may pause enrollments.
+    /// A horizon of 0 disables the switch. Only admin can call this, and
+    /// doing so counts as a check-in.
+    pub fn set_admin_liveness_horizon(
+        ctx: Context<UpdateChallengeStatus>,
+        horizon_seconds: i64,
+    ) -> Result<()> {
+        require!(horizon_seconds >= 0, ShootError::InvalidParameter);
+
+        let challenge = &mut ctx.accounts.challenge;
+        challenge.admin_liveness_horizon = horizon_seconds;
+        challenge.admin_last_check_in = Clock::get()?.unix_timestamp;
+
+        emit_cpi!(AdminLivenessHorizonSet {
+            challenge: challenge.key(),
+            horizon_seconds,
+        });
+
+        Ok(())
+    }
+
+    /// Pause enrollments on a challenge whose admin has missed its check-in
+    /// horizon. Permissionless — this is the only pre-approved emergency
+    /// action, so a lost admin key can never block it. Existing enrollments
+    /// and settlement are unaffected.
+    pub fn trigger_dead_man_switch(ctx: Context<TriggerDeadManSwitch>) -> Result<()> {
+        let challenge = &mut ctx.accounts.challenge;
+        require!(
+            challenge.admin_liveness_horizon > 0,
+            ShootError::DeadManSwitchDisabled
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let deadline = challenge
+            .admin_last_check_in
+            .checked_add(challenge.admin_liveness_horizon)
+            .ok_or(ShootError::Overflow)?;
+        require!(now > deadline, ShootError::AdminStillLive);
+
+        challenge.paused = true;
+
+        emit_cpi!(DeadManSwitchTriggered {
+            challenge: challenge.key(),
+            triggered_by: ctx.accounts.caller.key(),
+            admin_last_check_in: challenge.admin_last_check_in,
+        });
+
+        Ok(())
+    }
+
     // ── Agent Management ─────────────────────────────────────────────────
 
     /// Register an autonomous trading agent. The agent trades on behalf of
@@ -394,7 +508,7 @@ pub mod shoot {
         agent.last_trade_at = 0;
         agent.bump = ctx.bumps.agent;
 
-        emit!(AgentRegistered {
+        emit_cpi!(AgentRegistered {
             agent: agent.key(),
             owner: agent.owner,
             name: agent.name.clone(),
@@ -413,7 +527,7 @@ pub mod shoot {
         let agent = &mut ctx.accounts.agent;
         agent.strategy_hash = new_strategy_hash;
 
-        emit!(AgentStrategyUpdated {
+        emit_cpi!(AgentStrategyUpdated {
             agent: agent.key(),
             new_strategy_hash,
         });
@@ -431,7 +545,7 @@ pub mod shoot {
 
         agent.status = AgentStatus::Retired;
 
-        emit!(AgentRetired {
+        emit_cpi!(AgentRetired {
             agent: agent.key(),
             owner: agent.owner,
         });
@@ -471,7 +585,7 @@ pub mod shoot {
         agent.elo_rating = new_elo;
         agent.last_trade_at = Clock::get()?.unix_timestamp;
 
-        emit!(AgentStatsUpdated {
+        emit_cpi!(AgentStatsUpdated {
             agent: agent.key(),
             won,
             pnl_bps,
@@ -481,6 +595,112 @@ pub mod shoot {
 
         Ok(())
     }
+
+    // ── Bug Bounty ───────────────────────────────────────────────────────
+
+    /// Create the bug bounty escrow and its USDC vault. Only the admin can
+    /// call this; one bounty exists per admin.
+    pub fn initialize_bounty(ctx: Context<InitializeBounty>) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.admin = ctx.accounts.admin.key();
+        bounty.usdc_mint = ctx.accounts.usdc_mint.key();
+        bounty.vault = ctx.accounts.vault.key();
+        bounty.total_funded_usdc = 0;
+        bounty.total_paid_usdc = 0;
+        bounty.payout_count = 0;
+        bounty.created_at = Clock::get()?.unix_timestamp;
+        bounty.bump = ctx.bumps.bounty;
+        bounty.vault_bump = ctx.bumps.vault;
+
+        emit_cpi!(BountyInitialized {
+            bounty: bounty.key(),
+            admin: bounty.admin,
+            vault: bounty.vault,
+        });
+
+        Ok(())
+    }
+
+    /// Deposit USDC into the bounty escrow. Anyone can top up the bounty.
+    pub fn fund_bounty(ctx: Context<FundBounty>, amount_usdc: u64) -> Result<()> {
+        require!(amount_usdc > 0, ShootError::InvalidParameter);
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.funder_usdc.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount_usdc)?;
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.total_funded_usdc = bounty
+            .total_funded_usdc
+            .checked_add(amount_usdc)
+            .ok_or(ShootError::Overflow)?;
+
+        emit_cpi!(BountyFunded {
+            bounty: bounty.key(),
+            funder: ctx.accounts.funder.key(),
+            amount_usdc,
+            total_funded_usdc: bounty.total_funded_usdc,
+        });
+
+        Ok(())
+    }
+
+    /// Pay a whitehat from the bounty escrow. Only the admin can call this.
+    /// `report_hash` commits to the disclosure report so the payout can be
+    /// matched to it publicly without revealing its contents.
+    pub fn reward_whitehat(
+        ctx: Context<RewardWhitehat>,
+        amount_usdc: u64,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(amount_usdc > 0, ShootError::InvalidParameter);
+        require!(
+            ctx.accounts.vault.amount >= amount_usdc,
+            ShootError::InsufficientVaultBalance
+        );
+
+        let bounty_key = ctx.accounts.bounty.key();
+        let vault_seeds: &[&[u8]] = &[
+            b"bounty_vault",
+            bounty_key.as_ref(),
+            &[ctx.accounts.bounty.vault_bump],
+        ];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.whitehat_usdc.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(
+            CpiContext::new_with_signer(cpi_program, cpi_accounts, &[vault_seeds]),
+            amount_usdc,
+        )?;
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.total_paid_usdc = bounty
+            .total_paid_usdc
+            .checked_add(amount_usdc)
+            .ok_or(ShootError::Overflow)?;
+        bounty.payout_count = bounty
+            .payout_count
+            .checked_add(1)
+            .ok_or(ShootError::Overflow)?;
+
+        emit_cpi!(WhitehatRewarded {
+            bounty: bounty_key,
+            whitehat: ctx.accounts.whitehat.key(),
+            amount_usdc,
+            report_hash,
+            payout_count: bounty.payout_count,
+        });
+
+        Ok(())
+    }
 }
 
 // ── Account Structures ──────────────────────────────────────────────────────
@@ -506,6 +726,8 @@ pub struct Challenge {
     pub bump: u8,
     pub vault_bump: u8,
     pub paused: bool,
+    pub admin_last_check_in: i64,
+    pub admin_liveness_horizon: i64,
 }
 
 #[account]
@@ -552,6 +774,19 @@ pub struct Agent {
     pub bump: u8,
 }
 
+#[account]
+pub struct BountyVault {
+    pub admin: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub vault: Pubkey,
+    pub total_funded_usdc: u64,
+    pub total_paid_usdc: u64,
+    pub payout_count: u32,
+    pub created_at: i64,
+    pub bump: u8,
+    pub vault_bump: u8,
+}
+
 // ── Enums ───────────────────────────────────────────────────────────────────
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -642,6 +877,26 @@ pub struct ChallengePaused {
     pub paused: bool,
 }
 
+#[event]
+pub struct AdminCheckedIn {
+    pub challenge: Pubkey,
+    pub admin: Pubkey,
+    pub checked_in_at: i64,
+}
+
+#[event]
+pub struct AdminLivenessHorizonSet {
+    pub challenge: Pubkey,
+    pub horizon_seconds: i64,
+}
+
+#[event]
+pub struct DeadManSwitchTriggered {
+    pub challenge: Pubkey,
+    pub triggered_by: Pubkey,
+    pub admin_last_check_in: i64,
+}
+
 #[event]
 pub struct AgentRegistered {
     pub agent: Pubkey,
@@ -671,8 +926,33 @@ pub struct AgentStatsUpdated {
     pub new_elo: u32,
 }
 
+#[event]
+pub struct BountyInitialized {
+    pub bounty: Pubkey,
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct BountyFunded {
+    pub bounty: Pubkey,
+    pub funder: Pubkey,
+    pub amount_usdc: u64,
+    pub total_funded_usdc: u64,
+}
+
+#[event]
+pub struct WhitehatRewarded {
+    pub bounty: Pubkey,
+    pub whitehat: Pubkey,
+    pub amount_usdc: u64,
+    pub report_hash: [u8; 32],
+    pub payout_count: u32,
+}
+
 // ── Instruction Contexts ────────────────────────────────────────────────────
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(challenge_id: String)]
 pub struct InitializeChallenge<'info> {
@@ -708,6 +988,7 @@ pub struct InitializeChallenge<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Enroll<'info> {
     #[account(mut)]
@@ -742,6 +1023,7 @@ pub struct Enroll<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SubmitResult<'info> {
     #[account(
@@ -759,9 +1041,12 @@ pub struct SubmitResult<'info> {
     pub enrollment: Account<'info, Enrollment>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SettleChallenge<'info> {
+    /// Pays rent for the trader's USDC ATA if it does not exist yet.
     #[account(
+        mut,
         constraint = authority.key() == challenge.result_authority @ ShootError::Unauthorized
     )]
     pub authority: Signer<'info>,
@@ -779,10 +1064,13 @@ pub struct SettleChallenge<'info> {
     #[account(mut)]
     pub trader: UncheckedAccount<'info>,
 
+    /// Created on demand so payouts still land if the trader closed their
+    /// ATA (or never had one) after enrolling.
     #[account(
-        mut,
-        constraint = trader_usdc.mint == challenge.usdc_mint @ ShootError::WrongMint,
-        constraint = trader_usdc.owner == trader.key() @ ShootError::WrongOwner,
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = trader,
     )]
     pub trader_usdc: Account<'info, TokenAccount>,
 
@@ -794,8 +1082,16 @@ pub struct SettleChallenge<'info> {
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    #[account(
+        constraint = usdc_mint.key() == challenge.usdc_mint @ ShootError::WrongMint,
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimFundedStatus<'info> {
     #[account(mut)]
@@ -830,6 +1126,7 @@ pub struct ClaimFundedStatus<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateChallengeStatus<'info> {
     #[account(
@@ -841,6 +1138,17 @@ pub struct UpdateChallengeStatus<'info> {
     pub challenge: Account<'info, Challenge>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TriggerDeadManSwitch<'info> {
+    /// Any signer may trigger the switch once the admin horizon has lapsed.
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub challenge: Account<'info, Challenge>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterAgent<'info> {
     #[account(mut)]
@@ -858,6 +1166,7 @@ pub struct RegisterAgent<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateAgent<'info> {
     #[account(
@@ -869,6 +1178,7 @@ pub struct UpdateAgent<'info> {
     pub agent: Account<'info, Agent>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateAgentStats<'info> {
     #[account(
@@ -882,6 +1192,92 @@ pub struct UpdateAgentStats<'info> {
     pub agent: Account<'info, Agent>,
 }
 
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeBounty<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = BOUNTY_SPACE,
+        seeds = [b"bounty", admin.key().as_ref()],
+        bump
+    )]
+    pub bounty: Account<'info, BountyVault>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = usdc_mint,
+        token::authority = vault,
+        seeds = [b"bounty_vault", bounty.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundBounty<'info> {
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub bounty: Account<'info, BountyVault>,
+
+    #[account(
+        mut,
+        constraint = funder_usdc.mint == bounty.usdc_mint @ ShootError::WrongMint,
+        constraint = funder_usdc.owner == funder.key() @ ShootError::WrongOwner,
+    )]
+    pub funder_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == bounty.vault @ ShootError::WrongVault,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RewardWhitehat<'info> {
+    #[account(
+        constraint = admin.key() == bounty.admin @ ShootError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub bounty: Account<'info, BountyVault>,
+
+    /// CHECK: Whitehat receiving the reward — only used to bind the token account owner.
+    pub whitehat: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = whitehat_usdc.mint == bounty.usdc_mint @ ShootError::WrongMint,
+        constraint = whitehat_usdc.owner == whitehat.key() @ ShootError::WrongOwner,
+    )]
+    pub whitehat_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == bounty.vault @ ShootError::WrongVault,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
 // ── Errors ──────────────────────────────────────────────────────────────────
 
 #[error_code]
@@ -930,4 +1326,8 @@ pub enum ShootError {
     AgentNotActive,
     #[msg("Agent is currently enrolled in an active competition")]
     AgentEnrolledInCompetition,
+    #[msg("Dead-man switch is not configured for this challenge")]
+    DeadManSwitchDisabled,
+    #[msg("Admin has checked in within the liveness horizon")]
+    AdminStillLive,
 }
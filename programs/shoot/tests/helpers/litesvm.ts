@@ -16,10 +16,19 @@ export const PROGRAM_ID = new PublicKey(
   "4HVnwG8iz7wdUbEQDH8cYGD6EuxNmMuEbvCrz8Ke2iMG"
 );
 
+export const EVENT_AUTHORITY = PublicKey.findProgramAddressSync(
+  [Buffer.from("__event_authority")],
+  PROGRAM_ID
+)[0];
+
 export const TOKEN_PROGRAM_ID = new PublicKey(
   "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
 );
 
+export const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
+  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+);
+
 // ── Instruction Discriminators ──────────────────────────────────────────────
 
 function disc(name: string): Buffer {
@@ -39,6 +48,13 @@ const IX_DISC = {
   settleChallenge: disc("settle_challenge"),
   claimFundedStatus: disc("claim_funded_status"),
   updateChallengeStatus: disc("update_challenge_status"),
+  pauseChallenge: disc("pause_challenge"),
+  adminCheckIn: disc("admin_check_in"),
+  setAdminLivenessHorizon: disc("set_admin_liveness_horizon"),
+  triggerDeadManSwitch: disc("trigger_dead_man_switch"),
+  initializeBounty: disc("initialize_bounty"),
+  fundBounty: disc("fund_bounty"),
+  rewardWhitehat: disc("reward_whitehat"),
 };
 
 // ── PDA Helpers ─────────────────────────────────────────────────────────────
@@ -77,6 +93,20 @@ export function findFundedPda(trader: PublicKey): [PublicKey, number] {
   );
 }
 
+export function findBountyPda(admin: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bounty"), admin.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findBountyVaultPda(bountyPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bounty_vault"), bountyPda.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
 // ── Borsh Serialization Helpers ─────────────────────────────────────────────
 
 function encodeString(s: string): Buffer {
@@ -161,6 +191,8 @@ export function buildInitializeChallengeIx(
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -187,6 +219,8 @@ export function buildEnrollIx(
       { pubkey: vault, isSigner: false, isWritable: true },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -221,6 +255,8 @@ export function buildSubmitResultIx(
       { pubkey: authority, isSigner: true, isWritable: false },
       { pubkey: challengePda, isSigner: false, isWritable: false },
       { pubkey: enrollmentPda, isSigner: false, isWritable: true },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -232,6 +268,7 @@ export function buildSettleChallengeIx(
   trader: PublicKey,
   traderUsdc: PublicKey,
   vault: PublicKey,
+  usdcMint: PublicKey,
   payoutUsdc: number | bigint
 ): TransactionInstruction {
   const [enrollmentPda] = findEnrollmentPda(challengePda, trader);
@@ -241,7 +278,7 @@ export function buildSettleChallengeIx(
   return new TransactionInstruction({
     programId: PROGRAM_ID,
     keys: [
-      { pubkey: authority, isSigner: true, isWritable: false },
+      { pubkey: authority, isSigner: true, isWritable: true },
       { pubkey: challengePda, isSigner: false, isWritable: false },
       { pubkey: enrollmentPda, isSigner: false, isWritable: true },
       { pubkey: trader, isSigner: false, isWritable: true },
@@ -249,6 +286,14 @@ export function buildSettleChallengeIx(
       { pubkey: vault, isSigner: false, isWritable: true },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: usdcMint, isSigner: false, isWritable: false },
+      {
+        pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -287,6 +332,162 @@ export function buildClaimFundedStatusIx(
       { pubkey: enrollmentPda, isSigner: false, isWritable: false },
       { pubkey: fundedPda, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data,
+  });
+}
+
+export function buildPauseChallengeIx(
+  admin: PublicKey,
+  challengePda: PublicKey,
+  paused: boolean
+): TransactionInstruction {
+  const data = Buffer.concat([IX_DISC.pauseChallenge, encodeU8(paused ? 1 : 0)]);
+
+  return new TransactionInstruction({
+    programId: PROGRAM_ID,
+    keys: [
+      { pubkey: admin, isSigner: true, isWritable: false },
+      { pubkey: challengePda, isSigner: false, isWritable: true },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data,
+  });
+}
+
+export function buildAdminCheckInIx(
+  admin: PublicKey,
+  challengePda: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    programId: PROGRAM_ID,
+    keys: [
+      { pubkey: admin, isSigner: true, isWritable: false },
+      { pubkey: challengePda, isSigner: false, isWritable: true },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: IX_DISC.adminCheckIn,
+  });
+}
+
+export function buildSetAdminLivenessHorizonIx(
+  admin: PublicKey,
+  challengePda: PublicKey,
+  horizonSeconds: number | bigint
+): TransactionInstruction {
+  const data = Buffer.concat([
+    IX_DISC.setAdminLivenessHorizon,
+    encodeI64(horizonSeconds),
+  ]);
+
+  return new TransactionInstruction({
+    programId: PROGRAM_ID,
+    keys: [
+      { pubkey: admin, isSigner: true, isWritable: false },
+      { pubkey: challengePda, isSigner: false, isWritable: true },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data,
+  });
+}
+
+export function buildTriggerDeadManSwitchIx(
+  caller: PublicKey,
+  challengePda: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    programId: PROGRAM_ID,
+    keys: [
+      { pubkey: caller, isSigner: true, isWritable: false },
+      { pubkey: challengePda, isSigner: false, isWritable: true },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: IX_DISC.triggerDeadManSwitch,
+  });
+}
+
+export function buildInitializeBountyIx(
+  admin: PublicKey,
+  usdcMint: PublicKey
+): TransactionInstruction {
+  const [bountyPda] = findBountyPda(admin);
+  const [vaultPda] = findBountyVaultPda(bountyPda);
+
+  return new TransactionInstruction({
+    programId: PROGRAM_ID,
+    keys: [
+      { pubkey: admin, isSigner: true, isWritable: true },
+      { pubkey: bountyPda, isSigner: false, isWritable: true },
+      { pubkey: usdcMint, isSigner: false, isWritable: false },
+      { pubkey: vaultPda, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: IX_DISC.initializeBounty,
+  });
+}
+
+export function buildFundBountyIx(
+  funder: PublicKey,
+  bountyPda: PublicKey,
+  funderUsdc: PublicKey,
+  amountUsdc: number | bigint
+): TransactionInstruction {
+  const [vaultPda] = findBountyVaultPda(bountyPda);
+
+  const data = Buffer.concat([IX_DISC.fundBounty, encodeU64(amountUsdc)]);
+
+  return new TransactionInstruction({
+    programId: PROGRAM_ID,
+    keys: [
+      { pubkey: funder, isSigner: true, isWritable: false },
+      { pubkey: bountyPda, isSigner: false, isWritable: true },
+      { pubkey: funderUsdc, isSigner: false, isWritable: true },
+      { pubkey: vaultPda, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data,
+  });
+}
+
+export function buildRewardWhitehatIx(
+  admin: PublicKey,
+  bountyPda: PublicKey,
+  whitehat: PublicKey,
+  whitehatUsdc: PublicKey,
+  amountUsdc: number | bigint,
+  reportHash: Uint8Array
+): TransactionInstruction {
+  const [vaultPda] = findBountyVaultPda(bountyPda);
+
+  const data = Buffer.concat([
+    IX_DISC.rewardWhitehat,
+    encodeU64(amountUsdc),
+    Buffer.from(reportHash),
+  ]);
+
+  return new TransactionInstruction({
+    programId: PROGRAM_ID,
+    keys: [
+      { pubkey: admin, isSigner: true, isWritable: false },
+      { pubkey: bountyPda, isSigner: false, isWritable: true },
+      { pubkey: whitehat, isSigner: false, isWritable: false },
+      { pubkey: whitehatUsdc, isSigner: false, isWritable: true },
+      { pubkey: vaultPda, isSigner: false, isWritable: true },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data,
   });
@@ -313,6 +514,9 @@ export interface ChallengeAccount {
   usdcMint: PublicKey;
   bump: number;
   vaultBump: number;
+  paused: boolean;
+  adminLastCheckIn: bigint;
+  adminLivenessHorizon: bigint;
 }
 
 export function decodeChallenge(data: Buffer): ChallengeAccount {
@@ -361,6 +565,12 @@ export function decodeChallenge(data: Buffer): ChallengeAccount {
   const bump = data.readUInt8(offset);
   offset += 1;
   const vaultBump = data.readUInt8(offset);
+  offset += 1;
+  const paused = data.readUInt8(offset) === 1;
+  offset += 1;
+  const adminLastCheckIn = data.readBigInt64LE(offset);
+  offset += 8;
+  const adminLivenessHorizon = data.readBigInt64LE(offset);
 
   return {
     admin,
@@ -381,6 +591,9 @@ export function decodeChallenge(data: Buffer): ChallengeAccount {
     usdcMint,
     bump,
     vaultBump,
+    paused,
+    adminLastCheckIn,
+    adminLivenessHorizon,
   };
 }
 
@@ -474,6 +687,51 @@ export function decodeFundedTrader(data: Buffer): FundedTraderAccount {
   };
 }
 
+export interface BountyVaultAccount {
+  admin: PublicKey;
+  usdcMint: PublicKey;
+  vault: PublicKey;
+  totalFundedUsdc: bigint;
+  totalPaidUsdc: bigint;
+  payoutCount: number;
+  createdAt: bigint;
+  bump: number;
+  vaultBump: number;
+}
+
+export function decodeBountyVault(data: Buffer): BountyVaultAccount {
+  let offset = 8;
+  const admin = new PublicKey(data.subarray(offset, offset + 32));
+  offset += 32;
+  const usdcMint = new PublicKey(data.subarray(offset, offset + 32));
+  offset += 32;
+  const vault = new PublicKey(data.subarray(offset, offset + 32));
+  offset += 32;
+  const totalFundedUsdc = data.readBigUInt64LE(offset);
+  offset += 8;
+  const totalPaidUsdc = data.readBigUInt64LE(offset);
+  offset += 8;
+  const payoutCount = data.readUInt32LE(offset);
+  offset += 4;
+  const createdAt = data.readBigInt64LE(offset);
+  offset += 8;
+  const bump = data.readUInt8(offset);
+  offset += 1;
+  const vaultBump = data.readUInt8(offset);
+
+  return {
+    admin,
+    usdcMint,
+    vault,
+    totalFundedUsdc,
+    totalPaidUsdc,
+    payoutCount,
+    createdAt,
+    bump,
+    vaultBump,
+  };
+}
+
 // ── SVM Helpers ─────────────────────────────────────────────────────────────
 
 export function createTestSVM(): LiteSVM {
@@ -516,6 +774,15 @@ export function fetchFundedTrader(
   return decodeFundedTrader(data);
 }
 
+export function fetchBountyVault(
+  svm: LiteSVM,
+  address: PublicKey
+): BountyVaultAccount {
+  const data = fetchAccount(svm, address);
+  if (!data) throw new Error(`BountyVault account not found: ${address}`);
+  return decodeBountyVault(data);
+}
+
 export function sendTx(
   svm: LiteSVM,
   ix: TransactionInstruction | TransactionInstruction[],
@@ -562,6 +829,10 @@ export const SHOOT_ERRORS = {
   InvalidRevenueShare: 6017,
   StringTooLong: 6018,
   Overflow: 6019,
+  AgentNotActive: 6020,
+  AgentEnrolledInCompetition: 6021,
+  DeadManSwitchDisabled: 6022,
+  AdminStillLive: 6023,
 } as const;
 
 export interface TxFailure {
@@ -395,6 +395,7 @@ describe("shoot (Surfpool E2E)", function () {
         trader.publicKey,
         traderAta,
         vaultPda,
+        USDC_MINT,
         BigInt(20_000_000) // 20 USDC payout
       );
       await sendTx([settleIx], [resultAuthority]);
@@ -626,6 +627,7 @@ describe("shoot (Surfpool E2E)", function () {
         trader.publicKey,
         traderAta,
         vaultPda,
+        USDC_MINT,
         BigInt(20_000_000)
       );
       await sendTx([settleIx], [resultAuthority]);
@@ -14,6 +14,16 @@ import {
   buildSubmitResultIx,
   buildSettleChallengeIx,
   buildClaimFundedStatusIx,
+  buildSetAdminLivenessHorizonIx,
+  buildAdminCheckInIx,
+  buildPauseChallengeIx,
+  buildTriggerDeadManSwitchIx,
+  buildInitializeBountyIx,
+  buildFundBountyIx,
+  buildRewardWhitehatIx,
+  findBountyPda,
+  findBountyVaultPda,
+  fetchBountyVault,
   fetchChallenge,
   fetchEnrollment,
   fetchFundedTrader,
@@ -80,6 +90,29 @@ function createTokenAccount(
   return ata;
 }
 
+/** Create a raw SPL token account at a fresh, non-ATA address */
+function createNonAtaTokenAccount(
+  svm: LiteSVM,
+  owner: PublicKey,
+  mint: PublicKey,
+  amount: bigint
+): PublicKey {
+  const address = Keypair.generate().publicKey;
+  const data = Buffer.alloc(165);
+  mint.toBuffer().copy(data, 0);
+  owner.toBuffer().copy(data, 32);
+  data.writeBigUInt64LE(amount, 64);
+  data.writeUInt8(1, 108);
+  svm.setAccount(address, {
+    data: new Uint8Array(data),
+    executable: false,
+    lamports: BigInt(2_039_280),
+    owner: TOKEN_PROGRAM_ID,
+    rentEpoch: BigInt(0),
+  });
+  return address;
+}
+
 /** Read the amount from a token account */
 function getTokenBalance(svm: LiteSVM, tokenAccount: PublicKey): bigint {
   const acct = svm.getAccount(tokenAccount);
@@ -245,6 +278,7 @@ describe("shoot (LiteSVM)", () => {
         trader.publicKey,
         traderUsdc,
         vaultPda,
+        usdcMint,
         BigInt(20_000_000) // 20 USDC
       );
       sendTx(svm, settleIx, [resultAuthority]);
@@ -254,6 +288,90 @@ describe("shoot (LiteSVM)", () => {
       expect(enrollment.payoutUsdc).to.equal(BigInt(20_000_000));
     });
 
+    it("creates the trader's USDC ATA on settlement if missing", () => {
+      const ataId = "ata-payout-test";
+      const ataTrader = Keypair.generate();
+      svm.airdrop(ataTrader.publicKey, AIRDROP_AMOUNT);
+
+      sendTx(
+        svm,
+        buildInitializeChallengeIx(
+          authority.publicKey,
+          resultAuthority.publicKey,
+          usdcMint,
+          {
+            challengeId: ataId,
+            tierName: "Test",
+            entryFeeUsdc: 1_000,
+            profitTargetBps: 500,
+            maxDrawdownBps: 500,
+            dailyLossLimitBps: 300,
+            durationSeconds: 3600,
+            minCapitalUsd: 10_000_000,
+            participantCap: 10,
+          }
+        ),
+        [authority]
+      );
+      const [ataChallenge] = findChallengePda(authority.publicKey, ataId);
+      const [ataVault] = findVaultPda(ataChallenge);
+      const [ataEnrollment] = findEnrollmentPda(
+        ataChallenge,
+        ataTrader.publicKey
+      );
+
+      // Enroll from a non-ATA token account so the ATA never exists
+      const fundingAccount = createNonAtaTokenAccount(
+        svm,
+        ataTrader.publicKey,
+        usdcMint,
+        BigInt(100_000_000)
+      );
+      sendTx(
+        svm,
+        buildEnrollIx(
+          ataTrader.publicKey,
+          ataChallenge,
+          fundingAccount,
+          ataVault,
+          BigInt(100_000_000)
+        ),
+        [ataTrader]
+      );
+      sendTx(
+        svm,
+        buildSubmitResultIx(
+          resultAuthority.publicKey,
+          ataChallenge,
+          ataEnrollment,
+          EnrollmentStatus.Passed,
+          900,
+          100
+        ),
+        [resultAuthority]
+      );
+
+      const traderAta = findAta(ataTrader.publicKey, usdcMint);
+      expect(svm.getAccount(traderAta)).to.be.null;
+
+      sendTx(
+        svm,
+        buildSettleChallengeIx(
+          resultAuthority.publicKey,
+          ataChallenge,
+          ataTrader.publicKey,
+          traderAta,
+          ataVault,
+          usdcMint,
+          BigInt(1_000)
+        ),
+        [resultAuthority]
+      );
+
+      expect(getTokenBalance(svm, traderAta)).to.equal(BigInt(1_000));
+      expect(fetchEnrollment(svm, ataEnrollment).settled).to.be.true;
+    });
+
     it("submits result as failed (separate challenge)", () => {
       const failId = "fail-test-001";
       const failTrader = Keypair.generate();
@@ -350,6 +468,257 @@ describe("shoot (LiteSVM)", () => {
     });
   });
 
+  describe("dead-man switch", () => {
+    it("lets anyone pause enrollments once the admin misses its horizon", () => {
+      const dmsId = "dead-man-test";
+      sendTx(
+        svm,
+        buildInitializeChallengeIx(
+          authority.publicKey,
+          resultAuthority.publicKey,
+          usdcMint,
+          {
+            challengeId: dmsId,
+            tierName: "Test",
+            entryFeeUsdc: 1_000,
+            profitTargetBps: 500,
+            maxDrawdownBps: 500,
+            dailyLossLimitBps: 300,
+            durationSeconds: 3600,
+            minCapitalUsd: 10_000_000,
+            participantCap: 10,
+          }
+        ),
+        [authority]
+      );
+
+      const [dmsChallenge] = findChallengePda(authority.publicKey, dmsId);
+      const [dmsVault] = findVaultPda(dmsChallenge);
+      const keeper = Keypair.generate();
+      svm.airdrop(keeper.publicKey, AIRDROP_AMOUNT);
+
+      // Disabled by default
+      let err = sendTxExpectFail(
+        svm,
+        buildTriggerDeadManSwitchIx(keeper.publicKey, dmsChallenge),
+        [keeper]
+      );
+      expect(err.customErrorCode).to.equal(SHOOT_ERRORS.DeadManSwitchDisabled);
+
+      sendTx(
+        svm,
+        buildSetAdminLivenessHorizonIx(authority.publicKey, dmsChallenge, 3600),
+        [authority]
+      );
+      let challenge = fetchChallenge(svm, dmsChallenge);
+      expect(challenge.adminLivenessHorizon).to.equal(BigInt(3600));
+
+      // Admin is still within the horizon
+      const early = Keypair.generate();
+      svm.airdrop(early.publicKey, AIRDROP_AMOUNT);
+      err = sendTxExpectFail(
+        svm,
+        buildTriggerDeadManSwitchIx(early.publicKey, dmsChallenge),
+        [early]
+      );
+      expect(err.customErrorCode).to.equal(SHOOT_ERRORS.AdminStillLive);
+
+      // Warp past the horizon
+      const clock = svm.getClock();
+      clock.unixTimestamp = challenge.adminLastCheckIn + BigInt(3601);
+      svm.setClock(clock);
+
+      sendTx(
+        svm,
+        buildTriggerDeadManSwitchIx(keeper.publicKey, dmsChallenge),
+        [keeper]
+      );
+      challenge = fetchChallenge(svm, dmsChallenge);
+      expect(challenge.paused).to.be.true;
+
+      // New enrollments are now rejected
+      const late = Keypair.generate();
+      svm.airdrop(late.publicKey, AIRDROP_AMOUNT);
+      const lateUsdc = createTokenAccount(
+        svm,
+        late.publicKey,
+        usdcMint,
+        BigInt(100_000_000)
+      );
+      err = sendTxExpectFail(
+        svm,
+        buildEnrollIx(
+          late.publicKey,
+          dmsChallenge,
+          lateUsdc,
+          dmsVault,
+          BigInt(100_000_000)
+        ),
+        [late]
+      );
+      expect(err.customErrorCode).to.equal(SHOOT_ERRORS.ChallengePaused);
+
+      // Unpausing counts as a check-in, so the switch cannot re-fire at once
+      sendTx(
+        svm,
+        buildPauseChallengeIx(authority.publicKey, dmsChallenge, false),
+        [authority]
+      );
+      challenge = fetchChallenge(svm, dmsChallenge);
+      expect(challenge.paused).to.be.false;
+      expect(challenge.adminLastCheckIn).to.equal(clock.unixTimestamp);
+
+      const retrigger = Keypair.generate();
+      svm.airdrop(retrigger.publicKey, AIRDROP_AMOUNT);
+      err = sendTxExpectFail(
+        svm,
+        buildTriggerDeadManSwitchIx(retrigger.publicKey, dmsChallenge),
+        [retrigger]
+      );
+      expect(err.customErrorCode).to.equal(SHOOT_ERRORS.AdminStillLive);
+    });
+
+    it("admin_check_in pushes the deadline out", () => {
+      const checkInId = "check-in-test";
+      sendTx(
+        svm,
+        buildInitializeChallengeIx(
+          authority.publicKey,
+          resultAuthority.publicKey,
+          usdcMint,
+          {
+            challengeId: checkInId,
+            tierName: "Test",
+            entryFeeUsdc: 1_000,
+            profitTargetBps: 500,
+            maxDrawdownBps: 500,
+            dailyLossLimitBps: 300,
+            durationSeconds: 3600,
+            minCapitalUsd: 10_000_000,
+            participantCap: 10,
+          }
+        ),
+        [authority]
+      );
+      const [checkInChallenge] = findChallengePda(
+        authority.publicKey,
+        checkInId
+      );
+
+      sendTx(
+        svm,
+        buildSetAdminLivenessHorizonIx(
+          authority.publicKey,
+          checkInChallenge,
+          3600
+        ),
+        [authority]
+      );
+      const originalCheckIn = fetchChallenge(
+        svm,
+        checkInChallenge
+      ).adminLastCheckIn;
+
+      // Check in shortly before the original deadline
+      const clock = svm.getClock();
+      clock.unixTimestamp = originalCheckIn + BigInt(3500);
+      svm.setClock(clock);
+      sendTx(svm, buildAdminCheckInIx(authority.publicKey, checkInChallenge), [
+        authority,
+      ]);
+      const challenge = fetchChallenge(svm, checkInChallenge);
+      expect(challenge.adminLastCheckIn).to.equal(
+        originalCheckIn + BigInt(3500)
+      );
+
+      // Past the original deadline, but within the refreshed one
+      clock.unixTimestamp = originalCheckIn + BigInt(3601);
+      svm.setClock(clock);
+      const caller = Keypair.generate();
+      svm.airdrop(caller.publicKey, AIRDROP_AMOUNT);
+      const err = sendTxExpectFail(
+        svm,
+        buildTriggerDeadManSwitchIx(caller.publicKey, checkInChallenge),
+        [caller]
+      );
+      expect(err.customErrorCode).to.equal(SHOOT_ERRORS.AdminStillLive);
+    });
+  });
+
+  describe("bug bounty", () => {
+    it("escrows funding and pays a whitehat with a report hash", () => {
+      const [bountyPda] = findBountyPda(authority.publicKey);
+      const [bountyVault] = findBountyVaultPda(bountyPda);
+
+      sendTx(svm, buildInitializeBountyIx(authority.publicKey, usdcMint), [
+        authority,
+      ]);
+
+      const funder = Keypair.generate();
+      svm.airdrop(funder.publicKey, AIRDROP_AMOUNT);
+      const funderUsdc = createTokenAccount(
+        svm,
+        funder.publicKey,
+        usdcMint,
+        BigInt(100_000_000)
+      );
+      sendTx(
+        svm,
+        buildFundBountyIx(
+          funder.publicKey,
+          bountyPda,
+          funderUsdc,
+          BigInt(50_000_000)
+        ),
+        [funder]
+      );
+      expect(getTokenBalance(svm, bountyVault)).to.equal(BigInt(50_000_000));
+
+      const whitehat = Keypair.generate();
+      const whitehatUsdc = createTokenAccount(
+        svm,
+        whitehat.publicKey,
+        usdcMint,
+        BigInt(0)
+      );
+      const reportHash = new Uint8Array(32).fill(7);
+
+      // Only the admin may pay out
+      const err = sendTxExpectFail(
+        svm,
+        buildRewardWhitehatIx(
+          funder.publicKey,
+          bountyPda,
+          whitehat.publicKey,
+          whitehatUsdc,
+          BigInt(10_000_000),
+          reportHash
+        ),
+        [funder]
+      );
+      expect(err.customErrorCode).to.equal(SHOOT_ERRORS.Unauthorized);
+
+      sendTx(
+        svm,
+        buildRewardWhitehatIx(
+          authority.publicKey,
+          bountyPda,
+          whitehat.publicKey,
+          whitehatUsdc,
+          BigInt(10_000_000),
+          reportHash
+        ),
+        [authority]
+      );
+
+      expect(getTokenBalance(svm, whitehatUsdc)).to.equal(BigInt(10_000_000));
+      const bounty = fetchBountyVault(svm, bountyPda);
+      expect(bounty.totalFundedUsdc).to.equal(BigInt(50_000_000));
+      expect(bounty.totalPaidUsdc).to.equal(BigInt(10_000_000));
+      expect(bounty.payoutCount).to.equal(1);
+    });
+  });
+
   // ── Error Cases ─────────────────────────────────────────────────────────
 
   describe("error cases", () => {
@@ -43,7 +43,7 @@ import {
   fetchOpenLimitLong,
   fetchOpenLimitShort,
 } from "../lib/adrena/client.ts";
-import { SHOOT_PROGRAM_ID } from "../sdk/dist/index.js";
+import { SHOOT_PROGRAM_ID, SHOOT_EVENT_AUTHORITY } from "../sdk/dist/index.js";
 
 // ── Config ──────────────────────────────────────────────────────────────────
 
@@ -800,6 +800,8 @@ async function executeTool(
           { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
           { pubkey: agentPda, isSigner: false, isWritable: true },
           { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
         ],
         data: Buffer.concat([disc("register_agent"), encodeString(strategyName), Buffer.from(strategyHash)]),
       });
@@ -824,6 +826,8 @@ async function executeTool(
           { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
           { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
           { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
         ],
         data: Buffer.concat([
           disc("initialize_challenge"),
@@ -854,6 +858,8 @@ async function executeTool(
           { pubkey: vaultPda, isSigner: false, isWritable: true },
           { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
           { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
         ],
         data: Buffer.concat([disc("enroll"), encodeU64(500_000_000)]),
       });
@@ -884,6 +890,8 @@ async function executeTool(
           { pubkey: state.resultAuthority.publicKey, isSigner: true, isWritable: false },
           { pubkey: challengePda, isSigner: false, isWritable: false },
           { pubkey: enrollmentPda, isSigner: false, isWritable: true },
+          { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
         ],
         data: Buffer.concat([
           disc("submit_result"),
@@ -909,6 +917,8 @@ async function executeTool(
           { pubkey: state.resultAuthority.publicKey, isSigner: true, isWritable: false },
           { pubkey: challengePda, isSigner: false, isWritable: false },
           { pubkey: agentPda, isSigner: false, isWritable: true },
+          { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+          { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
         ],
         data: statsData,
       });
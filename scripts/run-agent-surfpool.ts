@@ -40,6 +40,7 @@ import {
   ReplayTap,
   DEFAULT_GUARDRAILS,
   SHOOT_PROGRAM_ID,
+  SHOOT_EVENT_AUTHORITY,
   ADRENA_PROGRAM_ID,
   ADRENA_MAIN_POOL,
   PerpBuilder,
@@ -334,6 +335,8 @@ async function main() {
       { pubkey: agent.publicKey, isSigner: true, isWritable: true },
       { pubkey: agentPda, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([
       disc("register_agent"),
@@ -366,6 +369,8 @@ async function main() {
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([
       disc("initialize_challenge"),
@@ -396,6 +401,8 @@ async function main() {
       { pubkey: vaultPda, isSigner: false, isWritable: true },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([
       disc("enroll"),
@@ -521,6 +528,8 @@ async function main() {
       { pubkey: resultAuthority.publicKey, isSigner: true, isWritable: false },
       { pubkey: challengePda, isSigner: false, isWritable: false },
       { pubkey: enrollmentPda, isSigner: false, isWritable: true },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data: Buffer.concat([
       disc("submit_result"),
@@ -554,7 +563,7 @@ async function main() {
     const settleIx = new TransactionInstruction({
       programId: SHOOT_PROGRAM_ID,
       keys: [
-        { pubkey: resultAuthority.publicKey, isSigner: true, isWritable: false },
+        { pubkey: resultAuthority.publicKey, isSigner: true, isWritable: true },
         { pubkey: challengePda, isSigner: false, isWritable: false },
         { pubkey: enrollmentPda, isSigner: false, isWritable: true },
         { pubkey: agent.publicKey, isSigner: false, isWritable: true },
@@ -562,6 +571,10 @@ async function main() {
         { pubkey: vaultPda, isSigner: false, isWritable: true },
         { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: usdcMint, isSigner: false, isWritable: false },
+        { pubkey: ASSOCIATED_TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+        { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
       ],
       data: Buffer.concat([
         disc("settle_challenge"),
@@ -595,6 +608,8 @@ async function main() {
       { pubkey: resultAuthority.publicKey, isSigner: true, isWritable: false },
       { pubkey: challengePda, isSigner: false, isWritable: false },
       { pubkey: agentPda, isSigner: false, isWritable: true },
+      { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+      { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
     ],
     data: statsData,
   });
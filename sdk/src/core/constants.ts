@@ -3,6 +3,11 @@ import { PublicKey } from "@solana/web3.js";
 export const SHOOT_PROGRAM_ID = new PublicKey(
   "4HVnwG8iz7wdUbEQDH8cYGD6EuxNmMuEbvCrz8Ke2iMG"
 );
+/** Anchor event-CPI authority; every Shoot instruction takes it second-to-last. */
+export const SHOOT_EVENT_AUTHORITY = PublicKey.findProgramAddressSync(
+  [Buffer.from("__event_authority")],
+  SHOOT_PROGRAM_ID
+)[0];
 export const ADRENA_PROGRAM_ID = new PublicKey(
   "13gDzEXCdocbj8iAiqrScGo47NiSuYENGsRqi3SEAwet"
 );
@@ -15,6 +15,7 @@ export type {
 export { DEFAULT_GUARDRAILS } from "./core/types.js";
 export {
   SHOOT_PROGRAM_ID,
+  SHOOT_EVENT_AUTHORITY,
   ADRENA_PROGRAM_ID,
   ADRENA_MAIN_POOL,
   PYTH_FEED_IDS,
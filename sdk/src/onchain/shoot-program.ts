@@ -6,7 +6,10 @@ import {
   TransactionInstruction,
 } from "@solana/web3.js";
 import { createHash } from "crypto";
-import { SHOOT_PROGRAM_ID } from "../core/constants.js";
+import {
+  SHOOT_EVENT_AUTHORITY,
+  SHOOT_PROGRAM_ID,
+} from "../core/constants.js";
 
 function ixDiscriminator(name: string): Buffer {
   return createHash("sha256").update(`global:${name}`).digest().subarray(0, 8);
@@ -52,6 +55,8 @@ export class ShootProgram {
         { pubkey: this.payer.publicKey, isSigner: true, isWritable: true },
         { pubkey: agent, isSigner: false, isWritable: true },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: SHOOT_EVENT_AUTHORITY, isSigner: false, isWritable: false },
+        { pubkey: SHOOT_PROGRAM_ID, isSigner: false, isWritable: false },
       ],
       data,
     });